@@ -0,0 +1,78 @@
+use std::collections::HashMap;
+
+use anyhow::Result;
+use k8s_openapi::api::core::v1::Event as K8sEvent;
+use kube::api::ListParams;
+use kube::{Api, Client};
+
+/// A Warning event reason aggregated across occurrences against a single
+/// target object, e.g. `FailedScheduling x3: 0/2 nodes are available...`.
+#[derive(Debug, Clone)]
+pub struct Cause {
+    pub reason: String,
+    pub message: String,
+    pub count: i32,
+}
+
+impl std::fmt::Display for Cause {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} x{}: {}", self.reason, self.count, self.message)
+    }
+}
+
+/// Key identifying a single involved object: `(namespace, kind, name)`.
+/// The namespace must be part of the key — two same-named pods in
+/// different namespaces are different objects, and dropping it would merge
+/// their events into one (wrong) set of likely causes. Cluster-scoped
+/// objects (nodes) key on an empty namespace.
+pub type TargetKey = (String, String, String);
+
+/// Fetch Warning events and group them by the object they're about,
+/// ranked by occurrence count.
+pub async fn warning_causes_by_target(
+    namespace: Option<&str>,
+) -> Result<HashMap<TargetKey, Vec<Cause>>> {
+    let client = Client::try_default().await?;
+    let events: Api<K8sEvent> = match namespace {
+        Some(ns) => Api::namespaced(client, ns),
+        None => Api::all(client),
+    };
+    let event_list = events.list(&ListParams::default()).await?;
+
+    let mut grouped: HashMap<TargetKey, HashMap<String, Cause>> = HashMap::new();
+
+    for event in event_list.items {
+        if event.type_.as_deref() != Some("Warning") {
+            continue;
+        }
+        let target_namespace = event.involved_object.namespace.clone().unwrap_or_default();
+        let kind = event.involved_object.kind.clone().unwrap_or_default();
+        let name = event.involved_object.name.clone().unwrap_or_default();
+        if kind.is_empty() || name.is_empty() {
+            continue;
+        }
+        let reason = event.reason.clone().unwrap_or_else(|| "Unknown".to_string());
+        let message = event.message.clone().unwrap_or_default();
+        let count = event.count.unwrap_or(1);
+
+        grouped
+            .entry((target_namespace, kind, name))
+            .or_default()
+            .entry(reason.clone())
+            .and_modify(|c: &mut Cause| c.count += count)
+            .or_insert(Cause {
+                reason,
+                message,
+                count,
+            });
+    }
+
+    Ok(grouped
+        .into_iter()
+        .map(|(target, by_reason)| {
+            let mut causes: Vec<Cause> = by_reason.into_values().collect();
+            causes.sort_by_key(|c| std::cmp::Reverse(c.count));
+            (target, causes)
+        })
+        .collect())
+}
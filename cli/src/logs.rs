@@ -0,0 +1,21 @@
+use anyhow::Result;
+use k8s_openapi::api::core::v1::Pod;
+use kube::api::LogParams;
+use kube::Api;
+
+/// Fetch the tail of a container's *previous* instance logs.
+pub async fn previous_logs(
+    pods: &Api<Pod>,
+    pod_name: &str,
+    container_name: &str,
+    tail_lines: i64,
+) -> Result<Vec<String>> {
+    let params = LogParams {
+        container: Some(container_name.to_string()),
+        tail_lines: Some(tail_lines),
+        previous: true,
+        ..Default::default()
+    };
+    let raw = pods.logs(pod_name, &params).await?;
+    Ok(raw.lines().map(str::to_string).collect())
+}
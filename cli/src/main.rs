@@ -1,3 +1,9 @@
+mod correlate;
+mod logs;
+mod model;
+mod server;
+mod watch;
+
 use clap::{Arg, Command};
 use colored::*;
 use k8s_openapi::api::core::v1::{Node, Pod, Service};
@@ -5,6 +11,22 @@ use kube::{Api, Client, ResourceExt};
 use kube::api::ListParams;
 use anyhow::Result;
 
+use model::{Report, ResourceEntry, Status};
+
+/// How a check's result should be surfaced: colored lines for a human
+/// terminal, or a single JSON document for CI pipelines and monitoring.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum OutputFormat {
+    Text,
+    Json,
+}
+
+impl OutputFormat {
+    fn is_text(self) -> bool {
+        self == OutputFormat::Text
+    }
+}
+
 #[tokio::main]
 async fn main() -> Result<()> {
     let matches = Command::new("KubeAI Doctor")
@@ -14,8 +36,7 @@ async fn main() -> Result<()> {
                 .short('c')
                 .long("check")
                 .value_name("RESOURCE")
-                .help("Run a health check on a specific Kubernetes resource (e.g., nodes, pods, services, events)")
-                .required(true),
+                .help("Run a health check on a specific Kubernetes resource (e.g., nodes, pods, services, events)"),
         )
         .arg(
             Arg::new("namespace")
@@ -24,50 +45,273 @@ async fn main() -> Result<()> {
                 .value_name("NAMESPACE")
                 .help("Specify a Kubernetes namespace (default: all namespaces)"),
         )
+        .arg(
+            Arg::new("output")
+                .short('o')
+                .long("output")
+                .value_name("FORMAT")
+                .value_parser(["text", "json"])
+                .default_value("text")
+                .help("Output format: 'text' for colored terminal output, 'json' for a machine-readable report"),
+        )
+        .arg(
+            Arg::new("watch")
+                .short('w')
+                .long("watch")
+                .action(clap::ArgAction::SetTrue)
+                .help("Stream changes to the checked resource instead of doing a single pass"),
+        )
+        .arg(
+            Arg::new("wait-for")
+                .long("wait-for")
+                .value_name("POD_NAME")
+                .help("Block until the named pod becomes Ready (only valid with --check pods)"),
+        )
+        .arg(
+            Arg::new("logs")
+                .long("logs")
+                .value_name("N")
+                .value_parser(clap::value_parser!(i64))
+                .help("Fetch the last N lines of previous-instance logs for crash-looping containers (pods only)"),
+        )
+        .subcommand(
+            Command::new("serve")
+                .about("Run checks on an interval and expose them over HTTP for dashboards and readiness probes")
+                .arg(
+                    Arg::new("namespace")
+                        .short('n')
+                        .long("namespace")
+                        .value_name("NAMESPACE")
+                        .help("Specify a Kubernetes namespace (default: all namespaces)"),
+                )
+                .arg(
+                    Arg::new("port")
+                        .short('p')
+                        .long("port")
+                        .value_name("PORT")
+                        .value_parser(clap::value_parser!(u16))
+                        .default_value("8080")
+                        .help("Port to listen on"),
+                )
+                .arg(
+                    Arg::new("interval")
+                        .short('i')
+                        .long("interval")
+                        .value_name("SECONDS")
+                        .value_parser(clap::value_parser!(u64))
+                        .default_value("15")
+                        .help("How often to refresh the health report, in seconds"),
+                ),
+        )
         .get_matches();
 
+    if let Some(serve_matches) = matches.subcommand_matches("serve") {
+        let namespace = serve_matches.get_one::<String>("namespace").cloned();
+        let port = *serve_matches.get_one::<u16>("port").unwrap();
+        let interval = *serve_matches.get_one::<u64>("interval").unwrap();
+        return server::serve(namespace, interval, port).await;
+    }
+
+    let Some(resource) = matches.get_one::<String>("check") else {
+        eprintln!("{} --check <RESOURCE> is required (or use the 'serve' subcommand).", "[ERROR]".red());
+        return Ok(());
+    };
+
     let namespace = matches.get_one::<String>("namespace").map(String::as_str);
-    
-    if let Some(resource) = matches.get_one::<String>("check") {
-        match resource.as_str() {
-            "nodes" => check_nodes().await?,
-            "pods" => check_pods(namespace).await?,
-            "services" => check_services(namespace).await?,
-            "events" => check_events(namespace).await?,
-            _ => eprintln!("{} Invalid resource. Use 'nodes', 'pods', 'services', or 'events'.", "[ERROR]".red()),
+    let output = match matches.get_one::<String>("output").map(String::as_str) {
+        Some("json") => OutputFormat::Json,
+        _ => OutputFormat::Text,
+    };
+    let watching = matches.get_flag("watch");
+    let wait_for = matches.get_one::<String>("wait-for").map(String::as_str);
+    let logs_tail = matches.get_one::<i64>("logs").copied();
+
+    if let Some(pod_name) = wait_for {
+        if resource != "pods" {
+            eprintln!("{} --wait-for is only supported with --check pods.", "[ERROR]".red());
+            return Ok(());
+        }
+        return watch::await_pod_ready(namespace, pod_name).await;
+    }
+
+    if watching {
+        return match resource.as_str() {
+            "nodes" => watch::watch_nodes().await,
+            "pods" => watch::watch_pods(namespace).await,
+            "services" => watch::watch_services(namespace).await,
+            "events" => watch::watch_events(namespace).await,
+            _ => {
+                eprintln!("{} Invalid resource. Use 'nodes', 'pods', 'services', or 'events'.", "[ERROR]".red());
+                Ok(())
+            }
+        };
+    }
+
+    let report = match resource.as_str() {
+        "nodes" => Some(check_nodes(output).await?),
+        "pods" => Some(check_pods(namespace, output, logs_tail).await?),
+        "services" => Some(check_services(namespace, output).await?),
+        "events" => Some(check_events(namespace, output).await?),
+        _ => {
+            eprintln!("{} Invalid resource. Use 'nodes', 'pods', 'services', or 'events'.", "[ERROR]".red());
+            None
+        }
+    };
+
+    if output == OutputFormat::Json {
+        if let Some(report) = report {
+            println!("{}", report.to_json()?);
         }
     }
     Ok(())
 }
 
-async fn check_nodes() -> Result<()> {
-    println!("{} Running health check on Kubernetes nodes...", "[INFO]".cyan());
+pub(crate) async fn check_nodes(output: OutputFormat) -> Result<Report> {
+    if output.is_text() {
+        println!("{} Running health check on Kubernetes nodes...", "[INFO]".cyan());
+    }
     let client = Client::try_default().await?;
     let nodes: Api<Node> = Api::all(client);
     let node_list = nodes.list(&Default::default()).await?;
+    let causes = correlate::warning_causes_by_target(None).await.unwrap_or_default();
 
-    let mut healthy = 0;
-    let mut unhealthy = 0;
+    let mut report = Report::new("nodes");
 
     for node in node_list.items {
         let name = node.name_any();
         let status = node.status.unwrap();
         let conditions = status.conditions.unwrap_or_default();
-        
-        if conditions.iter().any(|c| c.type_ == "Ready" && c.status == "True") {
-            println!("‚úÖ Node: {}", name.green());
-            healthy += 1;
-        } else {
-            println!("‚ùå Node: {} (NotReady)", name.red());
-            unhealthy += 1;
+
+        let ready = conditions.iter().any(|c| c.type_ == "Ready" && c.status == "True");
+        let mut entry = ResourceEntry::new(
+            "Node",
+            &name,
+            if ready { Status::Healthy } else { Status::Unhealthy },
+        );
+        for condition in &conditions {
+            entry = entry.with_detail(condition.type_.clone(), condition.status.clone());
+        }
+
+        if output.is_text() {
+            if ready {
+                println!("‚úÖ Node: {}", name.green());
+            } else {
+                println!("‚ùå Node: {} (NotReady)", name.red());
+            }
+        }
+
+        if !ready {
+            entry = attach_likely_causes(entry, "", "Node", &name, &causes, output);
         }
+        report.push(entry);
     }
-    println!("\n{} {} healthy, {} unhealthy", "[SUMMARY]".yellow(), healthy, unhealthy);
-    Ok(())
+
+    if output.is_text() {
+        println!(
+            "\n{} {} healthy, {} unhealthy",
+            "[SUMMARY]".yellow(),
+            report.healthy_count(),
+            report.unhealthy_count()
+        );
+    }
+    Ok(report)
+}
+
+/// A single reason a container is considered unhealthy, derived from its
+/// `ContainerStatus` rather than the coarse pod-level phase.
+#[derive(Debug, Clone)]
+pub(crate) enum ContainerIssue {
+    /// Container is stuck waiting, e.g. CrashLoopBackOff, ImagePullBackOff.
+    ContainerWaiting(String),
+    /// Container is running but has not passed its readiness probe yet.
+    NotReady,
+    /// Container has restarted at least once; carries the last known exit
+    /// reason so operators don't have to go digging for it separately.
+    Restarted {
+        count: i32,
+        exit_code: i32,
+        reason: String,
+    },
+    /// Container is currently terminated with a non-zero exit code.
+    TerminatedWithError(i32),
+}
+
+impl std::fmt::Display for ContainerIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ContainerIssue::ContainerWaiting(reason) => write!(f, "waiting ({reason})"),
+            ContainerIssue::NotReady => write!(f, "not ready"),
+            ContainerIssue::Restarted {
+                count,
+                exit_code,
+                reason,
+            } => write!(
+                f,
+                "restarted {count} time(s), last exit {exit_code} ({reason})"
+            ),
+            ContainerIssue::TerminatedWithError(code) => {
+                write!(f, "terminated with error (exit {code})")
+            }
+        }
+    }
+}
+
+/// Classify a single container's status into zero or more issues.
+pub(crate) fn classify_container(status: &k8s_openapi::api::core::v1::ContainerStatus) -> Vec<ContainerIssue> {
+    let mut issues = Vec::new();
+
+    if let Some(state) = &status.state {
+        if let Some(waiting) = &state.waiting {
+            if let Some(reason) = &waiting.reason {
+                issues.push(ContainerIssue::ContainerWaiting(reason.clone()));
+            }
+        }
+
+        if let Some(terminated) = &state.terminated {
+            if terminated.exit_code != 0 {
+                issues.push(ContainerIssue::TerminatedWithError(terminated.exit_code));
+            }
+        }
+
+        if state.running.is_some() && !status.ready {
+            issues.push(ContainerIssue::NotReady);
+        }
+    }
+
+    if status.restart_count > 0 {
+        if let Some(last_state) = &status.last_state {
+            if let Some(terminated) = &last_state.terminated {
+                issues.push(ContainerIssue::Restarted {
+                    count: status.restart_count,
+                    exit_code: terminated.exit_code,
+                    reason: terminated
+                        .reason
+                        .clone()
+                        .unwrap_or_else(|| "Unknown".to_string()),
+                });
+            }
+        }
+    }
+
+    issues
+}
+
+/// Whether a container is worth pulling previous-instance logs for: it has
+/// restarted at least once, or is waiting on a crash-loop-style reason. A
+/// container that died exactly once with no restart has no previous
+/// instance for the kubelet to return, so `TerminatedWithError` alone does
+/// not qualify.
+fn is_crash_indicator(status: &k8s_openapi::api::core::v1::ContainerStatus, issues: &[ContainerIssue]) -> bool {
+    status.restart_count > 0
+        || issues.iter().any(|issue| {
+            matches!(issue, ContainerIssue::ContainerWaiting(reason) if reason == "CrashLoopBackOff")
+        })
 }
 
-async fn check_pods(namespace: Option<&str>) -> Result<()> {
-    println!("{} Running health check on Kubernetes pods...", "[INFO]".cyan());
+pub(crate) async fn check_pods(namespace: Option<&str>, output: OutputFormat, logs_tail: Option<i64>) -> Result<Report> {
+    if output.is_text() {
+        println!("{} Running health check on Kubernetes pods...", "[INFO]".cyan());
+    }
     let client = Client::try_default().await?;
     let pods: Api<Pod> = if let Some(ns) = namespace {
         Api::namespaced(client, ns)
@@ -75,29 +319,136 @@ async fn check_pods(namespace: Option<&str>) -> Result<()> {
         Api::all(client)
     };
     let pod_list = pods.list(&ListParams::default()).await?;
+    let causes = correlate::warning_causes_by_target(namespace).await.unwrap_or_default();
 
-    let mut healthy = 0;
-    let mut unhealthy = 0;
+    let mut report = Report::new("pods");
 
     for pod in pod_list.items {
         let name = pod.name_any();
+        let pod_namespace = pod.namespace().unwrap_or_default();
         let status = pod.status.unwrap();
         let phase = status.phase.unwrap_or_else(|| "Unknown".to_string());
-        
-        if phase == "Running" {
-            println!("‚úÖ Pod: {}", name.green());
-            healthy += 1;
+
+        let container_statuses = status.container_statuses.unwrap_or_default();
+        let container_issues: Vec<ContainerIssue> = container_statuses
+            .iter()
+            .flat_map(classify_container)
+            .collect();
+
+        let healthy = phase == "Running" && container_issues.is_empty();
+        // A pod that's Running but failing readiness (no crash/restart/exit
+        // issues) is degraded, not fully unhealthy.
+        let degraded = !healthy
+            && phase == "Running"
+            && container_issues.iter().all(|i| matches!(i, ContainerIssue::NotReady));
+        let health = if healthy {
+            Status::Healthy
+        } else if degraded {
+            Status::Degraded
         } else {
-            println!("‚ùå Pod: {} (Status: {})", name.red(), phase.red());
-            unhealthy += 1;
+            Status::Unhealthy
+        };
+        let mut entry = ResourceEntry::new("Pod", &name, health).with_detail("phase", phase.clone());
+        for (i, issue) in container_issues.iter().enumerate() {
+            entry = entry.with_detail(format!("container_issue_{i}"), issue.to_string());
+        }
+
+        if output.is_text() {
+            if healthy {
+                println!("‚úÖ Pod: {}", name.green());
+            } else {
+                println!("‚ùå Pod: {} (Status: {})", name.red(), phase.red());
+                for issue in &container_issues {
+                    println!("   ‚Ü≥ {}", issue.to_string().red());
+                }
+            }
+        }
+
+        if let Some(tail_lines) = logs_tail {
+            for cs in &container_statuses {
+                let issues = classify_container(cs);
+                if !is_crash_indicator(cs, &issues) {
+                    continue;
+                }
+                match logs::previous_logs(&pods, &name, &cs.name, tail_lines).await {
+                    Ok(lines) => {
+                        if output.is_text() {
+                            println!("   {} previous logs for {}/{}:", "[LOGS]".yellow(), name, cs.name);
+                            for line in &lines {
+                                println!("     | {line}");
+                            }
+                        }
+                        entry = entry.with_detail(format!("logs_{}", cs.name), lines.join("\n"));
+                    }
+                    Err(e) => {
+                        if output.is_text() {
+                            eprintln!(
+                                "   {} could not fetch logs for {}/{}: {e}",
+                                "[WARN]".yellow(),
+                                name,
+                                cs.name
+                            );
+                        }
+                    }
+                }
+            }
         }
+
+        if !healthy {
+            entry = attach_likely_causes(entry, &pod_namespace, "Pod", &name, &causes, output);
+        }
+        report.push(entry);
     }
-    println!("\n{} {} healthy, {} unhealthy", "[SUMMARY]".yellow(), healthy, unhealthy);
-    Ok(())
+
+    if output.is_text() {
+        println!(
+            "\n{} {} healthy, {} unhealthy",
+            "[SUMMARY]".yellow(),
+            report.healthy_count(),
+            report.unhealthy_count()
+        );
+    }
+    Ok(report)
+}
+
+/// Look up ranked Warning causes for a single unhealthy resource and attach
+/// them as a "likely causes" detail, printing a short ranked list in text
+/// mode. No-op if nothing correlates.
+fn attach_likely_causes(
+    entry: ResourceEntry,
+    namespace: &str,
+    kind: &str,
+    name: &str,
+    causes: &std::collections::HashMap<correlate::TargetKey, Vec<correlate::Cause>>,
+    output: OutputFormat,
+) -> ResourceEntry {
+    let Some(causes) = causes.get(&(namespace.to_string(), kind.to_string(), name.to_string())) else {
+        return entry;
+    };
+    if causes.is_empty() {
+        return entry;
+    }
+
+    if output.is_text() {
+        println!("   {} likely causes:", "[CAUSE]".yellow());
+        for cause in causes.iter().take(3) {
+            println!("     ‚Ü≥ {}", cause.to_string().yellow());
+        }
+    }
+
+    let joined = causes
+        .iter()
+        .take(3)
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join("; ");
+    entry.with_detail("likely_causes", joined)
 }
 
-async fn check_services(namespace: Option<&str>) -> Result<()> {
-    println!("{} Running health check on Kubernetes services...", "[INFO]".cyan());
+pub(crate) async fn check_services(namespace: Option<&str>, output: OutputFormat) -> Result<Report> {
+    if output.is_text() {
+        println!("{} Running health check on Kubernetes services...", "[INFO]".cyan());
+    }
     let client = Client::try_default().await?;
     let services: Api<Service> = if let Some(ns) = namespace {
         Api::namespaced(client, ns)
@@ -106,15 +457,22 @@ async fn check_services(namespace: Option<&str>) -> Result<()> {
     };
     let service_list = services.list(&ListParams::default()).await?;
 
+    let mut report = Report::new("services");
+
     for service in service_list.items {
         let name = service.name_any();
-        println!("üîπ Service: {}", name.blue());
+        if output.is_text() {
+            println!("üîπ Service: {}", name.blue());
+        }
+        report.push(ResourceEntry::new("Service", &name, Status::Unknown));
     }
-    Ok(())
+    Ok(report)
 }
 
-async fn check_events(namespace: Option<&str>) -> Result<()> {
-    println!("{} Fetching recent Kubernetes events...", "[INFO]".cyan());
+pub(crate) async fn check_events(namespace: Option<&str>, output: OutputFormat) -> Result<Report> {
+    if output.is_text() {
+        println!("{} Fetching recent Kubernetes events...", "[INFO]".cyan());
+    }
     let client = Client::try_default().await?;
     let events: Api<k8s_openapi::api::core::v1::Event> = if let Some(ns) = namespace {
         Api::namespaced(client, ns)
@@ -123,10 +481,122 @@ async fn check_events(namespace: Option<&str>) -> Result<()> {
     };
     let event_list = events.list(&ListParams::default()).await?;
 
+    let mut report = Report::new("events");
+
     for event in event_list.items {
         let name = event.name_any();
         let message = event.message.unwrap_or_else(|| "No message".to_string());
-        println!("üì¢ Event: {} - {}", name.magenta(), message);
+        if output.is_text() {
+            println!("üì¢ Event: {} - {}", name.magenta(), message);
+        }
+        report.push(ResourceEntry::new("Event", &name, Status::Unknown).with_detail("message", message));
     }
-    Ok(())
-}
\ No newline at end of file
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use k8s_openapi::api::core::v1::{
+        ContainerState, ContainerStateRunning, ContainerStateTerminated, ContainerStateWaiting,
+        ContainerStatus,
+    };
+
+    #[test]
+    fn waiting_crash_loop_backoff_is_reported() {
+        let status = ContainerStatus {
+            state: Some(ContainerState {
+                waiting: Some(ContainerStateWaiting {
+                    reason: Some("CrashLoopBackOff".to_string()),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let issues = classify_container(&status);
+        assert!(matches!(
+            issues.as_slice(),
+            [ContainerIssue::ContainerWaiting(reason)] if reason == "CrashLoopBackOff"
+        ));
+    }
+
+    #[test]
+    fn running_but_not_ready_is_reported() {
+        let status = ContainerStatus {
+            ready: false,
+            state: Some(ContainerState {
+                running: Some(ContainerStateRunning::default()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let issues = classify_container(&status);
+        assert!(matches!(issues.as_slice(), [ContainerIssue::NotReady]));
+    }
+
+    #[test]
+    fn restarted_with_last_terminated_is_reported() {
+        let status = ContainerStatus {
+            restart_count: 3,
+            state: Some(ContainerState {
+                running: Some(ContainerStateRunning::default()),
+                ..Default::default()
+            }),
+            last_state: Some(ContainerState {
+                terminated: Some(ContainerStateTerminated {
+                    exit_code: 1,
+                    reason: Some("Error".to_string()),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }),
+            ready: true,
+            ..Default::default()
+        };
+
+        let issues = classify_container(&status);
+        assert!(matches!(
+            issues.as_slice(),
+            [ContainerIssue::Restarted { count: 3, exit_code: 1, reason }] if reason == "Error"
+        ));
+    }
+
+    #[test]
+    fn terminated_with_nonzero_exit_is_reported() {
+        let status = ContainerStatus {
+            state: Some(ContainerState {
+                terminated: Some(ContainerStateTerminated {
+                    exit_code: 137,
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let issues = classify_container(&status);
+        assert!(matches!(
+            issues.as_slice(),
+            [ContainerIssue::TerminatedWithError(137)]
+        ));
+    }
+
+    #[test]
+    fn terminated_with_zero_exit_is_not_an_issue() {
+        let status = ContainerStatus {
+            state: Some(ContainerState {
+                terminated: Some(ContainerStateTerminated {
+                    exit_code: 0,
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        assert!(classify_container(&status).is_empty());
+    }
+}
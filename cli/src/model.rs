@@ -0,0 +1,93 @@
+use std::collections::BTreeMap;
+
+use serde::Serialize;
+
+/// Overall health of a single resource, independent of its kind.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Status {
+    Healthy,
+    Degraded,
+    Unhealthy,
+    Unknown,
+}
+
+/// One checked resource (a node, a pod, a service, ...) and everything we
+/// found out about it. `details` is intentionally free-form so each
+/// `check_*` function can attach whatever is relevant (node conditions,
+/// container issues, event counts) without growing the schema.
+#[derive(Debug, Clone, Serialize)]
+pub struct ResourceEntry {
+    pub kind: String,
+    pub name: String,
+    pub status: Status,
+    pub details: BTreeMap<String, String>,
+}
+
+impl ResourceEntry {
+    pub fn new(kind: impl Into<String>, name: impl Into<String>, status: Status) -> Self {
+        Self {
+            kind: kind.into(),
+            name: name.into(),
+            status,
+            details: BTreeMap::new(),
+        }
+    }
+
+    pub fn with_detail(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.details.insert(key.into(), value.into());
+        self
+    }
+}
+
+/// The full result of a single `check` invocation, ready to be rendered as
+/// colored text for a human or serialized as one JSON document for CI.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct Report {
+    pub resource: String,
+    pub entries: Vec<ResourceEntry>,
+}
+
+impl Report {
+    pub fn new(resource: impl Into<String>) -> Self {
+        Self {
+            resource: resource.into(),
+            entries: Vec::new(),
+        }
+    }
+
+    pub fn push(&mut self, entry: ResourceEntry) {
+        self.entries.push(entry);
+    }
+
+    pub fn healthy_count(&self) -> usize {
+        self.entries
+            .iter()
+            .filter(|e| e.status == Status::Healthy)
+            .count()
+    }
+
+    pub fn unhealthy_count(&self) -> usize {
+        self.entries
+            .iter()
+            .filter(|e| e.status == Status::Unhealthy)
+            .count()
+    }
+
+    pub fn to_json(&self) -> serde_json::Result<String> {
+        serde_json::to_string_pretty(self)
+    }
+}
+
+/// A snapshot covering every resource kind the doctor knows how to check,
+/// as served by the `serve` subcommand's `/report` endpoint.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct DoctorReport {
+    pub reports: Vec<Report>,
+}
+
+impl DoctorReport {
+    pub fn unhealthy_count(&self) -> usize {
+        self.reports.iter().map(Report::unhealthy_count).sum()
+    }
+}
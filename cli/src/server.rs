@@ -0,0 +1,85 @@
+use std::net::SocketAddr;
+use std::time::Duration;
+
+use anyhow::Result;
+use axum::extract::State;
+use axum::routing::get;
+use axum::{Json, Router};
+use colored::*;
+use serde::Serialize;
+use tokio::sync::watch;
+
+use crate::model::DoctorReport;
+use crate::{check_events, check_nodes, check_pods, check_services, OutputFormat};
+
+/// Aggregate serving status, mirroring the gRPC health checking protocol's
+/// SERVING / NOT_SERVING / UNKNOWN convention.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+enum ServingStatus {
+    Serving,
+    NotServing,
+    Unknown,
+}
+
+#[derive(Clone)]
+struct AppState {
+    reports: watch::Receiver<DoctorReport>,
+}
+
+pub async fn serve(namespace: Option<String>, interval_secs: u64, port: u16) -> Result<()> {
+    let (tx, rx) = watch::channel(DoctorReport::default());
+
+    tokio::spawn(async move {
+        loop {
+            match collect_report(namespace.as_deref()).await {
+                Ok(report) => {
+                    if tx.send(report).is_err() {
+                        break;
+                    }
+                }
+                Err(e) => eprintln!("{} failed to refresh health report: {e}", "[ERROR]".red()),
+            }
+            tokio::time::sleep(Duration::from_secs(interval_secs)).await;
+        }
+    });
+
+    let state = AppState { reports: rx };
+    let app = Router::new()
+        .route("/healthz", get(healthz))
+        .route("/report", get(report))
+        .with_state(state);
+
+    let addr = SocketAddr::from(([0, 0, 0, 0], port));
+    println!("{} KubeAI Doctor serving diagnostics on {addr}", "[INFO]".cyan());
+    let listener = tokio::net::TcpListener::bind(addr).await?;
+    axum::serve(listener, app).await?;
+    Ok(())
+}
+
+async fn collect_report(namespace: Option<&str>) -> Result<DoctorReport> {
+    Ok(DoctorReport {
+        reports: vec![
+            check_nodes(OutputFormat::Json).await?,
+            check_pods(namespace, OutputFormat::Json, None).await?,
+            check_services(namespace, OutputFormat::Json).await?,
+            check_events(namespace, OutputFormat::Json).await?,
+        ],
+    })
+}
+
+async fn healthz(State(state): State<AppState>) -> Json<serde_json::Value> {
+    let report = state.reports.borrow().clone();
+    let status = if report.reports.is_empty() {
+        ServingStatus::Unknown
+    } else if report.unhealthy_count() == 0 {
+        ServingStatus::Serving
+    } else {
+        ServingStatus::NotServing
+    };
+    Json(serde_json::json!({ "status": status }))
+}
+
+async fn report(State(state): State<AppState>) -> Json<DoctorReport> {
+    Json(state.reports.borrow().clone())
+}
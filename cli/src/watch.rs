@@ -0,0 +1,180 @@
+use std::collections::HashMap;
+
+use anyhow::Result;
+use colored::*;
+use futures::{StreamExt, TryStreamExt};
+use k8s_openapi::api::core::v1::{Event as K8sEvent, Node, Pod, Service};
+use kube::runtime::wait::await_condition;
+use kube::runtime::watcher::{self, Event};
+use kube::{Api, Client, ResourceExt};
+
+use crate::{classify_container, ContainerIssue};
+
+fn api_for<K>(client: Client, namespace: Option<&str>) -> Api<K>
+where
+    K: kube::Resource<Scope = k8s_openapi::NamespaceResourceScope> + Clone + std::fmt::Debug + serde::de::DeserializeOwned,
+    K::DynamicType: Default,
+{
+    match namespace {
+        Some(ns) => Api::namespaced(client, ns),
+        None => Api::all(client),
+    }
+}
+
+/// Stream pod changes and print only deltas: a line whenever a pod's number
+/// of container issues changes (including the first time the pod is seen),
+/// rather than the whole list on every tick.
+pub async fn watch_pods(namespace: Option<&str>) -> Result<()> {
+    let client = Client::try_default().await?;
+    let pods: Api<Pod> = api_for(client, namespace);
+
+    println!("{} Watching pods for changes (Ctrl+C to stop)...", "[INFO]".cyan());
+
+    let mut stream = watcher::watcher(pods, watcher::Config::default()).boxed();
+    // Keyed by (namespace, name): without the namespace, two same-named
+    // pods in different namespaces would share one entry and clobber each
+    // other's issue count, the same bug fixed in correlate.rs's cause map.
+    let mut last_issue_count: HashMap<(String, String), usize> = HashMap::new();
+
+    while let Some(event) = stream.try_next().await? {
+        match event {
+            Event::Apply(pod) | Event::InitApply(pod) => {
+                let name = pod.name_any();
+                let key = (pod.namespace().unwrap_or_default(), name.clone());
+                let issues: Vec<ContainerIssue> = pod
+                    .status
+                    .as_ref()
+                    .and_then(|s| s.container_statuses.clone())
+                    .unwrap_or_default()
+                    .iter()
+                    .flat_map(classify_container)
+                    .collect();
+
+                let previous = last_issue_count.insert(key, issues.len());
+                if previous != Some(issues.len()) {
+                    if issues.is_empty() {
+                        println!("‚úÖ Pod {} is now healthy", name.green());
+                    } else {
+                        println!("‚ùå Pod {} became unhealthy:", name.red());
+                        for issue in &issues {
+                            println!("   ‚Ü≥ {}", issue.to_string().red());
+                        }
+                    }
+                }
+            }
+            Event::Delete(pod) => {
+                let name = pod.name_any();
+                last_issue_count.remove(&(pod.namespace().unwrap_or_default(), name.clone()));
+                println!("{} Pod {} deleted", "[INFO]".cyan(), name);
+            }
+            Event::Init | Event::InitDone => {}
+        }
+    }
+    Ok(())
+}
+
+/// Stream node changes and print a line whenever a node's `Ready` condition
+/// flips.
+pub async fn watch_nodes() -> Result<()> {
+    let client = Client::try_default().await?;
+    let nodes: Api<Node> = Api::all(client);
+
+    println!("{} Watching nodes for changes (Ctrl+C to stop)...", "[INFO]".cyan());
+
+    let mut stream = watcher::watcher(nodes, watcher::Config::default()).boxed();
+    let mut last_ready: HashMap<String, bool> = HashMap::new();
+
+    while let Some(event) = stream.try_next().await? {
+        match event {
+            Event::Apply(node) | Event::InitApply(node) => {
+                let name = node.name_any();
+                let ready = node
+                    .status
+                    .as_ref()
+                    .and_then(|s| s.conditions.as_ref())
+                    .is_some_and(|conditions| conditions.iter().any(|c| c.type_ == "Ready" && c.status == "True"));
+
+                if last_ready.insert(name.clone(), ready) != Some(ready) {
+                    if ready {
+                        println!("‚úÖ Node {} is now Ready", name.green());
+                    } else {
+                        println!("‚ùå Node {} went NotReady", name.red());
+                    }
+                }
+            }
+            Event::Delete(node) => {
+                let name = node.name_any();
+                last_ready.remove(&name);
+                println!("{} Node {} deleted", "[INFO]".cyan(), name);
+            }
+            Event::Init | Event::InitDone => {}
+        }
+    }
+    Ok(())
+}
+
+/// Stream service changes and print each addition/removal as it happens.
+pub async fn watch_services(namespace: Option<&str>) -> Result<()> {
+    let client = Client::try_default().await?;
+    let services: Api<Service> = api_for(client, namespace);
+
+    println!("{} Watching services for changes (Ctrl+C to stop)...", "[INFO]".cyan());
+
+    let mut stream = watcher::watcher(services, watcher::Config::default()).boxed();
+    while let Some(event) = stream.try_next().await? {
+        match event {
+            Event::Apply(service) | Event::InitApply(service) => {
+                println!("üîπ Service: {}", service.name_any().blue())
+            }
+            Event::Delete(service) => println!("{} Service {} deleted", "[INFO]".cyan(), service.name_any()),
+            Event::Init | Event::InitDone => {}
+        }
+    }
+    Ok(())
+}
+
+/// Stream events as they're emitted instead of listing a point-in-time
+/// snapshot.
+pub async fn watch_events(namespace: Option<&str>) -> Result<()> {
+    let client = Client::try_default().await?;
+    let events: Api<K8sEvent> = api_for(client, namespace);
+
+    println!("{} Watching events for changes (Ctrl+C to stop)...", "[INFO]".cyan());
+
+    let mut stream = watcher::watcher(events, watcher::Config::default()).boxed();
+    while let Some(event) = stream.try_next().await? {
+        if let Event::Apply(event) | Event::InitApply(event) = event {
+            let name = event.name_any();
+            let message = event.message.unwrap_or_else(|| "No message".to_string());
+            println!("üì¢ Event: {} - {}", name.magenta(), message);
+        }
+    }
+    Ok(())
+}
+
+/// True once the pod's `Ready` condition is `True` — unlike
+/// `conditions::is_pod_running`, this actually reflects readiness rather
+/// than just `status.phase == "Running"`, so a pod stuck failing its
+/// readiness probe doesn't satisfy the wait.
+fn pod_is_ready(pod: Option<&Pod>) -> bool {
+    pod.and_then(|p| p.status.as_ref())
+        .and_then(|s| s.conditions.as_ref())
+        .is_some_and(|conditions| conditions.iter().any(|c| c.type_ == "Ready" && c.status == "True"))
+}
+
+/// Block until the named pod becomes Ready, the way `kubectl wait` does.
+/// Requires `--namespace`: without one, the name alone can't disambiguate
+/// same-named pods in different namespaces.
+pub async fn await_pod_ready(namespace: Option<&str>, name: &str) -> Result<()> {
+    let Some(namespace) = namespace else {
+        anyhow::bail!("--wait-for requires --namespace to disambiguate which pod to wait for");
+    };
+
+    let client = Client::try_default().await?;
+    let pods: Api<Pod> = Api::namespaced(client, namespace);
+
+    println!("{} Waiting for pod {}/{} to become Ready...", "[INFO]".cyan(), namespace, name);
+    await_condition(pods, name, pod_is_ready).await?;
+    println!("‚úÖ Pod {}/{} is Ready", namespace, name.green());
+    Ok(())
+}